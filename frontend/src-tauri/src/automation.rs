@@ -0,0 +1,86 @@
+// Input simulation: types AI output directly into whatever application
+// currently has focus, so Sakura can act as a hands-free dictation/answer
+// tool instead of only displaying text in its own windows.
+
+use enigo::{Enigo, Keyboard, Key, Direction, Settings};
+use tauri::Emitter;
+
+#[tauri::command]
+pub fn type_text(app: tauri::AppHandle, text: String) -> Result<(), String> {
+    let mut enigo = Enigo::new(&Settings::default()).map_err(|e| e.to_string())?;
+    enigo.text(&text).map_err(|e| e.to_string())?;
+
+    println!("⌨️ Typed {} chars into focused app", text.chars().count());
+    let _ = app.emit("text_typed", ());
+    Ok(())
+}
+
+#[tauri::command]
+pub fn send_keys(app: tauri::AppHandle, combo: String) -> Result<(), String> {
+    let mut enigo = Enigo::new(&Settings::default()).map_err(|e| e.to_string())?;
+    let (modifiers, main_key) = parse_combo(&combo)?;
+
+    // Track which modifiers actually got pressed so we can always release
+    // them on the way out, even if a later step in the combo fails -- a
+    // bail-out mid-sequence would otherwise leave Ctrl/Alt/Shift physically
+    // "stuck down" in the simulated input stream.
+    let mut pressed = Vec::with_capacity(modifiers.len());
+    let result: Result<(), String> = (|| {
+        for m in &modifiers {
+            enigo.key(*m, Direction::Press).map_err(|e| e.to_string())?;
+            pressed.push(*m);
+        }
+        enigo.key(main_key, Direction::Press).map_err(|e| e.to_string())?;
+        enigo.key(main_key, Direction::Release).map_err(|e| e.to_string())?;
+        Ok(())
+    })();
+
+    for m in pressed.iter().rev() {
+        let _ = enigo.key(*m, Direction::Release);
+    }
+    result?;
+
+    println!("⌨️ Sent key combo: {}", combo);
+    let _ = app.emit("keys_sent", combo);
+    Ok(())
+}
+
+// Parses a combo string like "ctrl+shift+v" into the modifier keys to hold
+// and the single main key to press/release while they're held.
+fn parse_combo(combo: &str) -> Result<(Vec<Key>, Key), String> {
+    let mut parts: Vec<&str> = combo.split('+').map(|p| p.trim()).filter(|p| !p.is_empty()).collect();
+    let main = parts.pop().ok_or_else(|| "Empty key combo".to_string())?;
+
+    let modifiers = parts
+        .into_iter()
+        .map(parse_modifier)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((modifiers, parse_key(main)?))
+}
+
+fn parse_modifier(name: &str) -> Result<Key, String> {
+    match name.to_lowercase().as_str() {
+        "ctrl" | "control" => Ok(Key::Control),
+        "alt" => Ok(Key::Alt),
+        "shift" => Ok(Key::Shift),
+        "meta" | "cmd" | "super" | "win" => Ok(Key::Meta),
+        other => Err(format!("Unknown modifier: {}", other)),
+    }
+}
+
+fn parse_key(name: &str) -> Result<Key, String> {
+    if name.chars().count() == 1 {
+        return Ok(Key::Unicode(name.chars().next().unwrap()));
+    }
+
+    match name.to_lowercase().as_str() {
+        "enter" | "return" => Ok(Key::Return),
+        "tab" => Ok(Key::Tab),
+        "space" => Ok(Key::Space),
+        "escape" | "esc" => Ok(Key::Escape),
+        "backspace" => Ok(Key::Backspace),
+        "delete" | "del" => Ok(Key::Delete),
+        other => Err(format!("Unknown key: {}", other)),
+    }
+}
@@ -0,0 +1,59 @@
+// Runtime-configurable global shortcuts, persisted to settings.json so
+// users on conflicting keymaps aren't stuck with the hardcoded Alt+S /
+// Alt+F / Alt+M / Alt+T bindings.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Shortcuts {
+    pub quick_search: String,
+    pub full_mode: String,
+    pub hide_mode: String,
+    pub type_last_answer: String,
+}
+
+impl Default for Shortcuts {
+    fn default() -> Self {
+        Self {
+            quick_search: "Alt+S".to_string(),
+            full_mode: "Alt+F".to_string(),
+            hide_mode: "Alt+M".to_string(),
+            type_last_answer: "Alt+T".to_string(),
+        }
+    }
+}
+
+impl Shortcuts {
+    pub fn set(&mut self, action: &str, accelerator: String) -> Result<(), String> {
+        match action {
+            "quick_search" => self.quick_search = accelerator,
+            "full_mode" => self.full_mode = accelerator,
+            "hide_mode" => self.hide_mode = accelerator,
+            "type_last_answer" => self.type_last_answer = accelerator,
+            other => return Err(format!("Unknown shortcut action: {}", other)),
+        }
+        Ok(())
+    }
+
+    pub fn entries(&self) -> [(&'static str, &str); 4] {
+        [
+            ("quick_search", &self.quick_search),
+            ("full_mode", &self.full_mode),
+            ("hide_mode", &self.hide_mode),
+            ("type_last_answer", &self.type_last_answer),
+        ]
+    }
+}
+
+pub fn load_shortcuts(app: &tauri::AppHandle) -> Shortcuts {
+    crate::load_settings(app)
+        .get("shortcuts")
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_shortcuts(app: &tauri::AppHandle, shortcuts: &Shortcuts) {
+    if let Ok(value) = serde_json::to_value(shortcuts) {
+        crate::save_setting(app, "shortcuts", value);
+    }
+}
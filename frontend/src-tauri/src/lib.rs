@@ -3,13 +3,164 @@
 // Bubble: 64x64 widget pinned to bottom-right
 // Main: Interface window, hidden until triggered
 
-use std::process::{Command, Child, Stdio};
-use std::sync::Mutex;
+mod automation;
+mod config;
+mod secrets;
+
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use shared_child::SharedChild;
 use tauri::{Manager, WebviewWindow, PhysicalPosition, Emitter, Listener};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
+
+// Global sidecar process handle. `Arc<SharedChild>` lets the supervisor thread
+// `wait()` on the child while `force_quit`/`graceful_shutdown` kill it from
+// another thread without racing on ownership.
+static BACKEND: Mutex<Option<Arc<SharedChild>>> = Mutex::new(None);
 
-// Global sidecar process handle
-static BACKEND: Mutex<Option<Child>> = Mutex::new(None);
+// Last AI response shown to the user, kept around so the "type last answer"
+// global shortcut has something to inject without the frontend round-tripping it.
+static LAST_ANSWER: Mutex<Option<String>> = Mutex::new(None);
+
+#[tauri::command]
+fn set_last_answer(text: String) {
+    if let Ok(mut guard) = LAST_ANSWER.lock() {
+        *guard = Some(text);
+    }
+}
+
+// Set by graceful_shutdown/force_quit before killing the child so the
+// supervisor thread knows a crash-looking exit was actually intentional.
+static SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
+
+// Bumped every time a backend instance is (re)spawned so an outgoing
+// supervisor thread can tell it's been superseded rather than racing a
+// deliberate restart (e.g. `restart_backend`, the dev hot-reload watcher).
+static BACKEND_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+const BACKEND_RESTART_BASE_DELAY: Duration = Duration::from_secs(1);
+const BACKEND_RESTART_MAX_DELAY: Duration = Duration::from_secs(30);
+const BACKEND_STABLE_UPTIME: Duration = Duration::from_secs(60);
+
+// Logical (width, height) pairs for each window state the app drives.
+#[derive(Clone, Copy)]
+pub struct WindowSizes {
+    pub main_default: (f64, f64),
+    pub quick_search: (f64, f64),
+    pub quick_search_restore: (f64, f64),
+    pub full_mode: (f64, f64),
+}
+
+impl Default for WindowSizes {
+    fn default() -> Self {
+        Self {
+            main_default: (480.0, 640.0),
+            quick_search: (600.0, 60.0),
+            quick_search_restore: (1000.0, 800.0),
+            full_mode: (400.0, 600.0),
+        }
+    }
+}
+
+// Startup configuration threaded through backend spawn/health-check/shutdown
+// instead of the literal port and timeouts that used to live inline in `run()`.
+struct RunConfig {
+    backend_port: u16,
+    voice: bool,
+    health_timeout: Duration,
+    internet_check: bool,
+    window_sizes: WindowSizes,
+}
+
+impl RunConfig {
+    const fn defaults() -> Self {
+        Self {
+            backend_port: 8000,
+            voice: true,
+            health_timeout: Duration::from_secs(45),
+            internet_check: true,
+            window_sizes: WindowSizes {
+                main_default: (480.0, 640.0),
+                quick_search: (600.0, 60.0),
+                quick_search_restore: (1000.0, 800.0),
+                full_mode: (400.0, 600.0),
+            },
+        }
+    }
+}
+
+static RUN_CONFIG: Mutex<RunConfig> = Mutex::new(RunConfig::defaults());
+
+fn backend_port() -> u16 {
+    RUN_CONFIG.lock().map(|c| c.backend_port).unwrap_or(8000)
+}
+
+fn backend_url(path: &str) -> String {
+    format!("http://localhost:{}{}", backend_port(), path)
+}
+
+fn backend_health_url() -> String {
+    format!("http://127.0.0.1:{}/health", backend_port())
+}
+
+/// Chainable builder for configuring and running Sakura, mirroring the
+/// `runner(...).and_then(|a| a.run())` shape used for setup/teardown flows
+/// elsewhere: build up a config, then drive it with `.run()`. This lets
+/// startup logic (port, window sizes, health timeout, internet check) be reused
+/// with non-default settings, e.g. in integration tests on an alternate port.
+pub struct SakuraBuilder {
+    config: RunConfig,
+}
+
+impl Default for SakuraBuilder {
+    fn default() -> Self {
+        Self { config: RunConfig::defaults() }
+    }
+}
+
+impl SakuraBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn backend_port(mut self, port: u16) -> Self {
+        self.config.backend_port = port;
+        self
+    }
+
+    pub fn voice(mut self, enabled: bool) -> Self {
+        self.config.voice = enabled;
+        self
+    }
+
+    pub fn health_timeout(mut self, timeout: Duration) -> Self {
+        self.config.health_timeout = timeout;
+        self
+    }
+
+    pub fn internet_check(mut self, enabled: bool) -> Self {
+        self.config.internet_check = enabled;
+        self
+    }
+
+    pub fn window_sizes(mut self, sizes: WindowSizes) -> Self {
+        self.config.window_sizes = sizes;
+        self
+    }
+
+    /// Installs this configuration as the active `RUN_CONFIG` and drives the
+    /// Tauri app with it.
+    pub fn run(self) {
+        if let Ok(mut guard) = RUN_CONFIG.lock() {
+            *guard = self.config;
+        }
+        run_app();
+    }
+}
 
 #[tauri::command]
 fn get_backend_status() -> String {
@@ -25,6 +176,13 @@ fn get_backend_status() -> String {
     }
 }
 
+#[tauri::command]
+fn restart_backend(app: tauri::AppHandle) -> Result<(), String> {
+    println!("🔁 Manual backend restart requested");
+    supersede_backend();
+    spawn_supervised_backend(&app)
+}
+
 #[tauri::command]
 fn toggle_main_window(app: tauri::AppHandle) {
     if let Some(main_window) = app.get_webview_window("main") {
@@ -55,26 +213,42 @@ fn hide_main_window(app: tauri::AppHandle) {
 #[tauri::command]
 fn force_quit() {
     println!("💥 Force quitting app and backend...");
-    
+    SHUTTING_DOWN.store(true, Ordering::SeqCst);
+
     // Try graceful shutdown first (saves conversation history)
     let client = reqwest::blocking::Client::new();
-    if let Ok(_) = client.post("http://localhost:8000/shutdown")
+    if let Ok(_) = client.post(backend_url("/shutdown"))
         .timeout(std::time::Duration::from_millis(500))
-        .send() 
+        .send()
     {
         println!("✅ Graceful shutdown signal sent");
         std::thread::sleep(std::time::Duration::from_millis(300));
     }
-    
+
     // Kill the Python backend
+    kill_backend();
+    // Hard exit the Tauri app
+    std::process::exit(0);
+}
+
+// Kills and forgets the currently tracked backend child, if any.
+fn kill_backend() {
     if let Ok(mut guard) = BACKEND.lock() {
-        if let Some(ref mut child) = *guard {
+        if let Some(child) = guard.take() {
             let _ = child.kill();
         }
-        *guard = None;
     }
-    // Hard exit the Tauri app
-    std::process::exit(0);
+}
+
+// Bumps `BACKEND_GENERATION` *before* killing the tracked child, then kills
+// it. Bumping first (rather than leaving it to `spawn_supervised_backend`)
+// closes the window where the outgoing supervisor thread's `child.wait()`
+// returns from the kill, still sees the stale generation, and mistakes a
+// deliberate restart for a crash -- racing its own respawn against the
+// caller's.
+fn supersede_backend() {
+    BACKEND_GENERATION.fetch_add(1, Ordering::SeqCst);
+    kill_backend();
 }
 
 fn find_backend_dir() -> Option<PathBuf> {
@@ -113,25 +287,28 @@ fn find_backend_dir() -> Option<PathBuf> {
     None
 }
 
-fn start_backend(app: &tauri::App) -> Result<(), String> {
+// Resolves how to launch the backend (bundled sidecar in prod, venv/system
+// Python in dev) without actually spawning it, so the supervisor can call
+// this again on every respawn attempt.
+fn resolve_backend_command(app: &tauri::AppHandle) -> Result<Command, String> {
     // PRODUCTION MODE: Use bundled sidecar
     // Robust Discovery: Check multiple locations and names
     let exe_dir = std::env::current_exe().ok().and_then(|p| p.parent().map(|p| p.to_path_buf()));
     let res_dir = app.path().resource_dir().ok();
-    
+
     // Possible Filenames
     let suffixes = if cfg!(windows) {
         vec!["sakura-backend-x86_64-pc-windows-msvc.exe", "sakura-backend.exe"]
     } else {
         vec!["sakura-backend"]
     };
-    
+
     // Possible Directories
     let mut dirs = vec![];
     if let Some(d) = &exe_dir { dirs.push(d.clone()); } // Check root (flattened)
     if let Some(d) = &res_dir { dirs.push(d.clone()); } // Check resources/
     if let Some(d) = &res_dir { dirs.push(d.join("binaries")); } // Check resources/binaries/
-    
+
     // Find first match
     let mut sidecar_path: Option<PathBuf> = None;
     for dir in dirs {
@@ -149,8 +326,9 @@ fn start_backend(app: &tauri::App) -> Result<(), String> {
     if let Some(path) = sidecar_path {
         println!("🚀 Starting bundled backend sidecar...");
         println!("   Path: {:?}", path);
-        
+
         let mut cmd = Command::new(&path);
+        cmd.arg("--port").arg(backend_port().to_string());
         // HIDE CONSOLE WINDOW on Windows (Crucial for polished feel)
         #[cfg(windows)]
         {
@@ -158,41 +336,30 @@ fn start_backend(app: &tauri::App) -> Result<(), String> {
             const CREATE_NO_WINDOW: u32 = 0x08000000;
             cmd.creation_flags(CREATE_NO_WINDOW);
         }
-        
+
         // Output handling
         cmd.stdout(Stdio::inherit());
         cmd.stderr(Stdio::inherit());
-        
+
         // Set working directory to resource dir (or exe dir) for data access checks
         if let Some(wd) = res_dir.or(exe_dir) {
             cmd.current_dir(wd);
         }
-        
-        match cmd.spawn() {
-            Ok(child) => {
-                if let Ok(mut guard) = BACKEND.lock() {
-                    *guard = Some(child);
-                }
-                println!("✅ Sidecar backend started on port 8000");
-                return Ok(());
-            }
-            Err(e) => {
-                eprintln!("⚠️ Sidecar failed to spawn: {}", e);
-            }
-        }
+
+        return Ok(cmd);
     }
-    
+
     // DEV MODE: Use Python with venv
     let backend_dir = find_backend_dir()
         .ok_or_else(|| "Could not find backend/server.py".to_string())?;
-    
+
     let server_py = backend_dir.join("server.py");
-    
+
     // V10: Use venv Python (PA/Scripts/python.exe on Windows)
     let venv_python = backend_dir.parent()
         .map(|root| root.join("PA").join("Scripts").join(if cfg!(windows) { "python.exe" } else { "python" }))
         .filter(|p| p.exists());
-    
+
     let python_cmd = if let Some(venv_py) = venv_python {
         println!("🐍 Using venv Python: {:?}", venv_py);
         venv_py.to_string_lossy().to_string()
@@ -200,82 +367,393 @@ fn start_backend(app: &tauri::App) -> Result<(), String> {
         println!("⚠️ Venv not found, falling back to system Python");
         if cfg!(windows) { "python".to_string() } else { "python3".to_string() }
     };
-    
+
     println!("🐍 Starting Python backend (dev mode)...");
     println!("   Script: {:?}", server_py);
-    
+
+    let port = backend_port();
+    let voice = RUN_CONFIG.lock().map(|c| c.voice).unwrap_or(true);
+
     let mut cmd = Command::new(&python_cmd);
     cmd.arg(&server_py);
-    cmd.arg("--voice"); // Enable Voice Mode by default
+    cmd.arg("--port").arg(port.to_string());
+    if voice {
+        cmd.arg("--voice"); // Enable Voice Mode by default
+    }
     cmd.current_dir(&backend_dir);
     cmd.env("PYTHONPATH", &backend_dir);
     cmd.stdout(Stdio::inherit());
     cmd.stderr(Stdio::inherit());
-    
-    match cmd.spawn() {
-        Ok(child) => {
-            if let Ok(mut guard) = BACKEND.lock() {
-                *guard = Some(child);
+
+    Ok(cmd)
+}
+
+// Spawns the backend once via `SharedChild`, stores it, and hands the shared
+// handle back so the caller can supervise it.
+fn spawn_backend_once(app: &tauri::AppHandle) -> Result<Arc<SharedChild>, String> {
+    let mut cmd = resolve_backend_command(app)?;
+
+    // Inject provider API keys from the OS keyring rather than baking them
+    // into files or relying on the caller's plaintext env.
+    let (found, missing) = secrets::load_required_secrets();
+    for (key, value) in found {
+        cmd.env(key, value);
+    }
+    if !missing.is_empty() {
+        println!("⚠️ Missing secrets: {:?}", missing);
+        if let Some(main) = app.get_webview_window("main") {
+            let _ = main.emit("secrets_missing", missing);
+        }
+    }
+
+    let child = SharedChild::spawn(cmd).map_err(|e| format!("Failed to start backend: {}", e))?;
+    let child = Arc::new(child);
+    if let Ok(mut guard) = BACKEND.lock() {
+        *guard = Some(child.clone());
+    }
+    println!("✅ Backend started on port {} (pid {})", backend_port(), child.id());
+    Ok(child)
+}
+
+// Spawns the backend and launches the supervisor thread that restarts it
+// with exponential backoff if it ever exits unexpectedly. Used at startup,
+// by the `restart_backend` command, and by the dev hot-reload watcher.
+fn spawn_supervised_backend(app: &tauri::AppHandle) -> Result<(), String> {
+    // Bumping the generation here means a previous supervisor thread (if
+    // any), once its now-killed child's wait() returns, will see it's been
+    // superseded and stand down instead of racing to respawn its own.
+    let my_gen = BACKEND_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let child = spawn_backend_once(app)?;
+    let app_handle = app.clone();
+
+    std::thread::spawn(move || {
+        let mut child = child;
+        let mut backoff = BACKEND_RESTART_BASE_DELAY;
+
+        loop {
+            let started_at = Instant::now();
+            let status = child.wait();
+
+            if SHUTTING_DOWN.load(Ordering::SeqCst) {
+                println!("🛑 Supervisor: shutdown in progress, not respawning");
+                return;
+            }
+
+            if BACKEND_GENERATION.load(Ordering::SeqCst) != my_gen {
+                println!("ℹ️ Supervisor: superseded by a newer backend instance, standing down");
+                return;
+            }
+
+            match status {
+                Ok(status) if status.success() => {
+                    println!("ℹ️ Backend exited cleanly, supervisor standing down");
+                    return;
+                }
+                Ok(status) => eprintln!("💥 Backend crashed: {:?}", status),
+                Err(e) => eprintln!("💥 Backend wait() failed: {}", e),
+            }
+
+            if let Some(main) = app_handle.get_webview_window("main") {
+                let _ = main.emit("backend_crashed", ());
+            }
+
+            if started_at.elapsed() >= BACKEND_STABLE_UPTIME {
+                backoff = BACKEND_RESTART_BASE_DELAY;
+            }
+
+            println!("⏳ Supervisor: respawning backend in {:?}", backoff);
+            std::thread::sleep(backoff);
+            backoff = (backoff * 2).min(BACKEND_RESTART_MAX_DELAY);
+
+            // A concurrent restart/reload can bump the generation and spawn
+            // its own replacement while we were asleep; re-check right
+            // before respawning so we don't clobber BACKEND with a second,
+            // now-stale backend process.
+            if BACKEND_GENERATION.load(Ordering::SeqCst) != my_gen {
+                println!("ℹ️ Supervisor: superseded by a newer backend instance, standing down");
+                return;
+            }
+
+            match spawn_backend_once(&app_handle) {
+                Ok(new_child) => child = new_child,
+                Err(e) => {
+                    eprintln!("⚠️ Supervisor: respawn failed: {}", e);
+                    // Keep retrying on the same backoff schedule rather than
+                    // giving up, in case the failure is transient (e.g. a
+                    // port still being released).
+                }
             }
-            println!("✅ Backend started on port 8000");
-            Ok(())
         }
-        Err(e) => {
-            let msg = format!("Failed to start backend: {}", e);
-            eprintln!("❌ {}", msg);
-            Err(msg)
+    });
+
+    Ok(())
+}
+
+// Dev hot-reload is on by default in debug builds (`cfg!(debug_assertions)`)
+// and off in release builds. SAKURA_DEV_WATCH overrides this in either kind
+// of build: "1"/"true" turns it on, any other value turns it off.
+fn dev_watch_enabled() -> bool {
+    match std::env::var("SAKURA_DEV_WATCH") {
+        Ok(v) => v == "1" || v.eq_ignore_ascii_case("true"),
+        Err(_) => cfg!(debug_assertions),
+    }
+}
+
+// Restarts the backend in place (used by the dev watcher): politely asks it
+// to shut down, kills the process, and respawns it under a fresh supervisor.
+fn reload_backend(app: &tauri::AppHandle) {
+    let client = reqwest::blocking::Client::new();
+    let _ = client.post(backend_url("/shutdown"))
+        .timeout(Duration::from_millis(500))
+        .send();
+    std::thread::sleep(Duration::from_millis(300));
+    supersede_backend();
+
+    match spawn_supervised_backend(app) {
+        Ok(_) => {
+            if let Some(main) = app.get_webview_window("main") {
+                let _ = main.emit("backend_reloaded", ());
+            }
+            println!("✅ Dev watcher: backend reloaded");
         }
+        Err(e) => eprintln!("⚠️ Dev watcher: reload failed: {}", e),
     }
 }
 
+// Watches the discovered backend directory for `.py` changes (debounced
+// ~500ms to coalesce editor save bursts) and hot-reloads the Python process
+// so dev-mode edits don't require a full app restart.
+fn spawn_dev_backend_watcher(app: tauri::AppHandle) {
+    if !dev_watch_enabled() {
+        return;
+    }
+    let Some(backend_dir) = find_backend_dir() else {
+        println!("ℹ️ Dev watcher: no backend dir found, skipping");
+        return;
+    };
+
+    std::thread::spawn(move || {
+        use notify_debouncer_mini::{new_debouncer, notify::RecursiveMode};
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut debouncer = match new_debouncer(Duration::from_millis(500), tx) {
+            Ok(d) => d,
+            Err(e) => {
+                eprintln!("⚠️ Dev watcher: failed to create debouncer: {}", e);
+                return;
+            }
+        };
+
+        if let Err(e) = debouncer.watcher().watch(&backend_dir, RecursiveMode::Recursive) {
+            eprintln!("⚠️ Dev watcher: failed to watch {:?}: {}", backend_dir, e);
+            return;
+        }
+        println!("👀 Dev watcher: watching {:?} for .py changes", backend_dir);
+
+        for result in rx {
+            let Ok(events) = result else { continue };
+            let py_changed = events.iter().any(|e| {
+                e.path.extension().map(|ext| ext == "py").unwrap_or(false)
+            });
+            if !py_changed {
+                continue;
+            }
+            println!("🔁 Dev watcher: backend source changed, reloading...");
+            reload_backend(&app);
+        }
+    });
+}
+
 fn graceful_shutdown() {
     println!("🛑 Shutting down backend...");
-    
+    SHUTTING_DOWN.store(true, Ordering::SeqCst);
+
     let client = reqwest::blocking::Client::new();
-    let _ = client.post("http://localhost:8000/shutdown")
+    let _ = client.post(backend_url("/shutdown"))
         .timeout(std::time::Duration::from_millis(500))
         .send();
-    
+
     std::thread::sleep(std::time::Duration::from_millis(300));
-    
-    if let Ok(mut guard) = BACKEND.lock() {
-        if let Some(ref mut child) = *guard {
-            let _ = child.kill();
-            println!("🛑 Backend process terminated");
-        }
-        *guard = None;
-    }
-    
+
+    kill_backend();
+    println!("🛑 Backend process terminated");
+
     // Force exit the application
     std::process::exit(0);
 }
 
-fn position_bubble_bottom_right(bubble: &WebviewWindow) {
-    // Get primary monitor and position bubble to bottom-right
-    if let Some(monitor) = bubble.primary_monitor().ok().flatten() {
+#[tauri::command]
+fn set_bubble_pinned(app: tauri::AppHandle, enabled: bool) -> Result<(), String> {
+    if let Some(bubble) = app.get_webview_window("bubble") {
+        bubble.set_visible_on_all_workspaces(enabled).map_err(|e| e.to_string())?;
+        bubble.set_always_on_top(enabled).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+// Name of the monitor the user last picked for the bubble, persisted in
+// settings.json so a hotplug/restart remembers the preference.
+static BUBBLE_MONITOR_NAME: Mutex<Option<String>> = Mutex::new(None);
+
+fn settings_file(app: &tauri::AppHandle) -> Option<PathBuf> {
+    app.path().app_config_dir().ok().map(|dir| dir.join("settings.json"))
+}
+
+pub(crate) fn load_settings(app: &tauri::AppHandle) -> serde_json::Value {
+    settings_file(app)
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_else(|| serde_json::json!({}))
+}
+
+pub(crate) fn save_setting(app: &tauri::AppHandle, key: &str, value: serde_json::Value) {
+    let Some(path) = settings_file(app) else { return };
+    let mut settings = load_settings(app);
+    if let Some(obj) = settings.as_object_mut() {
+        obj.insert(key.to_string(), value);
+    }
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let _ = std::fs::write(path, settings.to_string());
+}
+
+fn load_bubble_monitor_name(app: &tauri::AppHandle) -> Option<String> {
+    load_settings(app).get("bubble_monitor").and_then(|v| v.as_str()).map(|s| s.to_string())
+}
+
+// Picks the monitor whose name contains `target` (case-insensitive
+// substring match, mirroring awesome-wm's screen-name matching), falling
+// back to the primary monitor when there's no match or no preference set.
+fn select_monitor(bubble: &WebviewWindow, target: Option<&str>) -> Option<tauri::Monitor> {
+    if let Some(target) = target {
+        let target = target.to_lowercase();
+        if let Ok(monitors) = bubble.available_monitors() {
+            if let Some(m) = monitors.into_iter().find(|m| {
+                m.name().map(|n| n.to_lowercase().contains(&target)).unwrap_or(false)
+            }) {
+                return Some(m);
+            }
+        }
+    }
+    bubble.primary_monitor().ok().flatten()
+}
+
+fn position_bubble_bottom_right(bubble: &WebviewWindow, target_monitor: Option<&str>) {
+    if let Some(monitor) = select_monitor(bubble, target_monitor) {
         let screen_size = monitor.size();
         let scale = monitor.scale_factor();
-        
+        let origin = monitor.position();
+
         // Calculate bottom-right position (physical pixels)
         // 220px window (contains 64px bubble + menu space)
         let bubble_size = (220.0 * scale) as i32;
         let margin = (20.0 * scale) as i32;
         let taskbar_height = (50.0 * scale) as i32;
-        
-        let x = screen_size.width as i32 - bubble_size - margin;
-        let y = screen_size.height as i32 - bubble_size - taskbar_height;
-        
-        println!("📍 Positioning bubble to ({}, {}) on {}x{} screen", 
-            x, y, screen_size.width, screen_size.height);
-        
+
+        let x = origin.x + screen_size.width as i32 - bubble_size - margin;
+        let y = origin.y + screen_size.height as i32 - bubble_size - taskbar_height;
+
+        println!("📍 Positioning bubble to ({}, {}) on {}x{} screen ({:?})",
+            x, y, screen_size.width, screen_size.height, monitor.name());
+
         let _ = bubble.set_position(PhysicalPosition::new(x, y));
     } else {
         println!("⚠️ Could not detect monitor, using default position");
     }
 }
 
+// Snapshot of the monitor layout used to detect hotplug/resolution changes
+// from the polling thread below.
+fn monitor_layout_signature(bubble: &WebviewWindow) -> String {
+    bubble.available_monitors().ok().map(|monitors| {
+        monitors.iter()
+            .map(|m| format!("{}:{}x{}@{}", m.name().cloned().unwrap_or_default(), m.size().width, m.size().height, m.scale_factor()))
+            .collect::<Vec<_>>()
+            .join(",")
+    }).unwrap_or_default()
+}
+
+// Polls the monitor set/geometry and re-pins the bubble to the bottom-right
+// corner of the configured monitor whenever it changes (resolution change,
+// monitor unplug, new monitor attached).
+fn spawn_monitor_watcher(app: tauri::AppHandle) {
+    std::thread::spawn(move || {
+        let mut last_signature = String::new();
+        loop {
+            std::thread::sleep(Duration::from_secs(2));
+
+            if let Some(bubble) = app.get_webview_window("bubble") {
+                let signature = monitor_layout_signature(&bubble);
+                if signature != last_signature {
+                    if !last_signature.is_empty() {
+                        println!("🖥️ Monitor layout changed, repositioning bubble");
+                    }
+                    last_signature = signature;
+                    let target = BUBBLE_MONITOR_NAME.lock().ok().and_then(|g| g.clone());
+                    position_bubble_bottom_right(&bubble, target.as_deref());
+                }
+            }
+        }
+    });
+}
+
+#[tauri::command]
+fn set_bubble_monitor(app: tauri::AppHandle, name: String) -> Result<(), String> {
+    if let Ok(mut guard) = BUBBLE_MONITOR_NAME.lock() {
+        *guard = Some(name.clone());
+    }
+    save_setting(&app, "bubble_monitor", serde_json::Value::String(name.clone()));
+    if let Some(bubble) = app.get_webview_window("bubble") {
+        position_bubble_bottom_right(&bubble, Some(&name));
+    }
+    Ok(())
+}
+
+// Currently-registered accelerator for each configurable action
+// ("quick_search"/"full_mode"/"hide_mode"/"type_last_answer"), so the shortcut handler can
+// dispatch by action name and `rebind_shortcut` knows what to unregister.
+static ACTION_SHORTCUTS: Mutex<Option<HashMap<String, Shortcut>>> = Mutex::new(None);
+
+#[tauri::command]
+fn rebind_shortcut(app: tauri::AppHandle, action: String, accelerator: String) -> Result<(), String> {
+    let new_shortcut: Shortcut = accelerator.parse().map_err(|e| format!("Invalid accelerator: {:?}", e))?;
+    let global_shortcut = app.global_shortcut();
+
+    let mut guard = ACTION_SHORTCUTS.lock().map_err(|_| "Shortcut registry poisoned".to_string())?;
+    let map = guard.get_or_insert_with(HashMap::new);
+
+    let old_shortcut = match map.get(&action) {
+        Some(shortcut) => *shortcut,
+        None => return Err(format!("Unknown shortcut action: {}", action)),
+    };
+
+    // Register the new accelerator before touching the old one: if
+    // registration fails (e.g. it collides with another app's global
+    // hotkey), the action stays bound to its old shortcut instead of
+    // ending up with no binding at all while ACTION_SHORTCUTS still
+    // claims the stale one is live.
+    global_shortcut.register(new_shortcut).map_err(|e| e.to_string())?;
+    let _ = global_shortcut.unregister(old_shortcut);
+    map.insert(action.clone(), new_shortcut);
+    drop(guard);
+
+    let mut shortcuts = config::load_shortcuts(&app);
+    shortcuts.set(&action, accelerator)?;
+    config::save_shortcuts(&app, &shortcuts);
+
+    Ok(())
+}
+
+/// Thin entry point that drives the app with `SakuraBuilder`'s defaults.
+/// Use `SakuraBuilder` directly to override the port, window sizes, or
+/// startup timeouts (e.g. for integration tests on an alternate port).
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    SakuraBuilder::default().run();
+}
+
+fn run_app() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_autostart::init(tauri_plugin_autostart::MacosLauncher::LaunchAgent, Some(vec![])))
@@ -284,39 +762,80 @@ pub fn run() {
             toggle_main_window,
             show_main_window,
             hide_main_window,
-            force_quit
+            force_quit,
+            restart_backend,
+            set_last_answer,
+            automation::type_text,
+            automation::send_keys,
+            set_bubble_pinned,
+            set_bubble_monitor,
+            secrets::set_secret,
+            secrets::get_secret,
+            secrets::delete_secret,
+            rebind_shortcut
         ])
         .setup(|app| {
-            // Start Python backend (sidecar in prod, python in dev)
-            if let Err(e) = start_backend(app) {
+            // Start Python backend (sidecar in prod, python in dev) and
+            // supervise it so a crash doesn't leave Sakura talking to a dead port.
+            if let Err(e) = spawn_supervised_backend(&app.handle()) {
                 eprintln!("Warning: {}", e);
             }
-            
+            spawn_dev_backend_watcher(app.handle().clone());
+
             // Register Global Shortcut (Shift+S) for Quick Search
             #[cfg(desktop)]
             {
-                use tauri_plugin_global_shortcut::{Code, Modifiers, ShortcutState};
-                
+                use tauri_plugin_global_shortcut::ShortcutState;
+
+                // Load user-configured shortcuts (falls back to Alt+S/Alt+F/Alt+M/Alt+T)
+                // and register them so the handler below can dispatch by action
+                // name instead of matching hardcoded key codes.
+                let shortcuts_cfg = config::load_shortcuts(&app.handle());
+                let mut action_map = HashMap::new();
+                let mut builder = tauri_plugin_global_shortcut::Builder::new();
+
+                for (action, accelerator) in shortcuts_cfg.entries() {
+                    match accelerator.parse::<Shortcut>() {
+                        Ok(shortcut) => {
+                            builder = builder.with_shortcut(shortcut)?;
+                            action_map.insert(action.to_string(), shortcut);
+                        }
+                        Err(e) => eprintln!("⚠️ Invalid configured shortcut for {}: {:?}", action, e),
+                    }
+                }
+
+                if let Ok(mut guard) = ACTION_SHORTCUTS.lock() {
+                    *guard = Some(action_map);
+                }
+
                 app.handle().plugin(
-                    tauri_plugin_global_shortcut::Builder::new()
-                        .with_shortcut("Alt+S")?
-                        .with_shortcut("Alt+F")?
-                        .with_shortcut("Alt+M")? // V10: Hide Mode
+                    builder
                         .with_handler(move |app, shortcut, event| {
                             if event.state == ShortcutState::Pressed {
+                                let action = ACTION_SHORTCUTS.lock().ok().and_then(|guard| {
+                                    guard.as_ref().and_then(|map| {
+                                        map.iter()
+                                            .find(|entry| entry.1 == shortcut)
+                                            .map(|entry| entry.0.clone())
+                                    })
+                                });
+
+                                let sizes = RUN_CONFIG.lock().map(|c| c.window_sizes).unwrap_or_default();
+
                                 if let Some(window) = app.get_webview_window("main") {
-                                    
-                                    // Alt+S: Quick Search Toggle
-                                    if shortcut.matches(Modifiers::ALT, Code::KeyS) {
-                                        println!("⌨️ Global Shortcut Alt+S pressed");
+                                    // Quick Search Toggle
+                                    if action.as_deref() == Some("quick_search") {
+                                        println!("⌨️ Global Shortcut pressed (Quick Search)");
                                         if window.is_visible().unwrap_or(false) {
                                             // Hide AND Restore default size (so next normal open is big)
                                             let _ = window.hide();
-                                            let _ = window.set_size(tauri::Size::Logical(tauri::LogicalSize { width: 1000.0, height: 800.0 }));
+                                            let (w, h) = sizes.quick_search_restore;
+                                            let _ = window.set_size(tauri::Size::Logical(tauri::LogicalSize { width: w, height: h }));
                                             let _ = window.center();
                                         } else {
                                             // Show Small
-                                            let _ = window.set_size(tauri::Size::Logical(tauri::LogicalSize { width: 600.0, height: 60.0 }));
+                                            let (w, h) = sizes.quick_search;
+                                            let _ = window.set_size(tauri::Size::Logical(tauri::LogicalSize { width: w, height: h }));
                                             let _ = window.center();
                                             let _ = window.show();
                                             let _ = window.set_focus();
@@ -324,20 +843,21 @@ pub fn run() {
                                         }
                                     }
 
-                                    // Alt+F: Force Full Mode
-                                    if shortcut.matches(Modifiers::ALT, Code::KeyF) {
-                                        println!("⌨️ Global Shortcut Alt+F pressed");
-                                        let _ = window.set_size(tauri::Size::Logical(tauri::LogicalSize { width: 400.0, height: 600.0 }));
+                                    // Force Full Mode
+                                    if action.as_deref() == Some("full_mode") {
+                                        println!("⌨️ Global Shortcut pressed (Full Mode)");
+                                        let (w, h) = sizes.full_mode;
+                                        let _ = window.set_size(tauri::Size::Logical(tauri::LogicalSize { width: w, height: h }));
                                         let _ = window.center();
                                         let _ = window.show();
                                         let _ = window.set_focus();
                                         let _ = window.emit("full_mode_trigger", ()); // Reset frontend state
                                     }
                                 }
-                                
-                                // Alt+M: Hide Mode (Toggle Bubble)
-                                if shortcut.matches(Modifiers::ALT, Code::KeyM) {
-                                    println!("⌨️ Global Shortcut Alt+M pressed (Hide Mode)");
+
+                                // Hide Mode (Toggle Bubble)
+                                if action.as_deref() == Some("hide_mode") {
+                                    println!("⌨️ Global Shortcut pressed (Hide Mode)");
                                     if let Some(bubble) = app.get_webview_window("bubble") {
                                         if bubble.is_visible().unwrap_or(false) {
                                             let _ = bubble.hide();
@@ -352,6 +872,20 @@ pub fn run() {
                                         }
                                     }
                                 }
+
+                                // Type last answer into whatever app has focus
+                                if action.as_deref() == Some("type_last_answer") {
+                                    println!("⌨️ Global Shortcut pressed (Type Last Answer)");
+                                    let answer = LAST_ANSWER.lock().ok().and_then(|g| g.clone());
+                                    if let Some(text) = answer {
+                                        match automation::type_text(app.clone(), text) {
+                                            Ok(_) => println!("⌨️ Typed last answer into focused app"),
+                                            Err(e) => eprintln!("⚠️ Failed to type last answer: {}", e),
+                                        }
+                                    } else {
+                                        println!("ℹ️ No last answer stored yet");
+                                    }
+                                }
                             }
                         })
                         .build(),
@@ -362,11 +896,20 @@ pub fn run() {
             // use tauri_plugin_autostart::ManagerExt;
             // let _ = app.handle().autolaunch().enable();
             
-            // Position bubble to bottom-right
+            // Position bubble to bottom-right (on the remembered monitor) and
+            // keep it pinned above fullscreen apps and visible across every
+            // virtual desktop / Space
+            let saved_monitor = load_bubble_monitor_name(&app.handle());
+            if let Ok(mut guard) = BUBBLE_MONITOR_NAME.lock() {
+                *guard = saved_monitor.clone();
+            }
             if let Some(bubble) = app.get_webview_window("bubble") {
-                position_bubble_bottom_right(&bubble);
+                position_bubble_bottom_right(&bubble, saved_monitor.as_deref());
+                let _ = bubble.set_visible_on_all_workspaces(true);
+                let _ = bubble.set_always_on_top(true);
             }
-            
+            spawn_monitor_watcher(app.handle().clone());
+
             // Listen for toggle_main event from bubble window
             let app_handle = app.handle().clone();
             app.listen("toggle_main", move |_event| {
@@ -374,9 +917,10 @@ pub fn run() {
                     if main_window.is_visible().unwrap_or(false) {
                         let _ = main_window.hide();
                     } else {
-                        // Reset to normal size if opening via bubble? 
+                        // Reset to normal size if opening via bubble?
                         // Or keep last state? Let's reset to default main window size for normal toggle
-                        let _ = main_window.set_size(tauri::Size::Logical(tauri::LogicalSize { width: 480.0, height: 640.0 }));
+                        let (w, h) = RUN_CONFIG.lock().map(|c| c.window_sizes.main_default).unwrap_or((480.0, 640.0));
+                        let _ = main_window.set_size(tauri::Size::Logical(tauri::LogicalSize { width: w, height: h }));
                          // Reposition might be needed if it was centered... let's let OS handle or center?
                          // Ideally we want it draggable. 
                          // Let's just show it. The user manages position usually unless we force it.
@@ -390,45 +934,51 @@ pub fn run() {
             
             // V10: Check internet connectivity BEFORE waiting for backend
             let client = reqwest::blocking::Client::new();
-            println!("🌐 Checking internet connectivity...");
-            let mut has_internet = false;
-            
-            for attempt in 0..30 { // Wait up to 30 seconds for internet
-                if let Ok(resp) = client.get("https://www.google.com")
-                    .timeout(std::time::Duration::from_secs(2))
-                    .send() 
-                {
-                    if resp.status().is_success() {
-                        println!("✅ Internet connected!");
-                        has_internet = true;
-                        break;
+            let internet_check = RUN_CONFIG.lock().map(|c| c.internet_check).unwrap_or(true);
+
+            if internet_check {
+                println!("🌐 Checking internet connectivity...");
+                let mut has_internet = false;
+
+                for attempt in 0..30 { // Wait up to 30 seconds for internet
+                    if let Ok(resp) = client.get("https://www.google.com")
+                        .timeout(std::time::Duration::from_secs(2))
+                        .send()
+                    {
+                        if resp.status().is_success() {
+                            println!("✅ Internet connected!");
+                            has_internet = true;
+                            break;
+                        }
                     }
-                }
-                
-                if attempt == 0 {
-                    println!("📡 No internet detected. Sakura requires internet to function.");
-                    println!("   Waiting for connection...");
-                    
-                    // Emit event to frontend to show "No Internet" message
-                    if let Some(main) = app.get_webview_window("main") {
-                        let _ = main.emit("no_internet", ());
+
+                    if attempt == 0 {
+                        println!("📡 No internet detected. Sakura requires internet to function.");
+                        println!("   Waiting for connection...");
+
+                        // Emit event to frontend to show "No Internet" message
+                        if let Some(main) = app.get_webview_window("main") {
+                            let _ = main.emit("no_internet", ());
+                        }
                     }
+
+                    std::thread::sleep(std::time::Duration::from_secs(1));
+                }
+
+                if !has_internet {
+                    eprintln!("❌ No internet connection after 30 seconds. Some features may not work.");
                 }
-                
-                std::thread::sleep(std::time::Duration::from_secs(1));
-            }
-            
-            if !has_internet {
-                eprintln!("❌ No internet connection after 30 seconds. Some features may not work.");
             }
-            
+
             // Wait for backend to be ready (Poll /health)
-            // NOTE: SmartAssistant init takes 5-15s, so use generous timeout
+            // NOTE: SmartAssistant init takes 5-15s, so use a generous, configurable timeout
             println!("⏳ Waiting for backend to start...");
             let mut ready = false;
-            
-            for _ in 0..45 { // Try for 45 seconds
-                if let Ok(resp) = client.get("http://127.0.0.1:8000/health").send() {
+            let health_timeout = RUN_CONFIG.lock().map(|c| c.health_timeout).unwrap_or(Duration::from_secs(45));
+            let health_url = backend_health_url();
+
+            for _ in 0..health_timeout.as_secs() {
+                if let Ok(resp) = client.get(&health_url).send() {
                     if resp.status().is_success() {
                         println!("✅ Backend ready!");
                         ready = true;
@@ -437,7 +987,7 @@ pub fn run() {
                 }
                 std::thread::sleep(std::time::Duration::from_secs(1));
             }
-            
+
             if !ready {
                 eprintln!("⚠️ Backend startup timed out or failed health check");
             }
@@ -472,3 +1022,40 @@ pub fn run() {
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_applies_config_over_defaults() {
+        let sizes = WindowSizes {
+            main_default: (100.0, 200.0),
+            quick_search: (300.0, 40.0),
+            quick_search_restore: (900.0, 700.0),
+            full_mode: (350.0, 500.0),
+        };
+
+        let builder = SakuraBuilder::new()
+            .backend_port(9001)
+            .voice(false)
+            .health_timeout(Duration::from_secs(5))
+            .internet_check(false)
+            .window_sizes(sizes);
+
+        assert_eq!(builder.config.backend_port, 9001);
+        assert_eq!(builder.config.voice, false);
+        assert_eq!(builder.config.health_timeout, Duration::from_secs(5));
+        assert_eq!(builder.config.internet_check, false);
+        assert_eq!(builder.config.window_sizes.main_default, (100.0, 200.0));
+    }
+
+    #[test]
+    fn builder_defaults_match_run_config_defaults() {
+        let builder = SakuraBuilder::new();
+        let defaults = RunConfig::defaults();
+
+        assert_eq!(builder.config.backend_port, defaults.backend_port);
+        assert_eq!(builder.config.health_timeout, defaults.health_timeout);
+    }
+}
@@ -0,0 +1,80 @@
+// LLM provider API keys, backed by the OS secret store (Secret Service /
+// libsecret on Linux, Keychain on macOS, Credential Manager on Windows via
+// the `keyring` crate) so they never land in plaintext config or env files.
+
+use keyring::Entry;
+
+const SERVICE: &str = "sakura";
+
+// Env var names the Python sidecar expects to find its provider keys under.
+// Anything missing here is surfaced to the frontend via `secrets_missing` so
+// it can prompt the user to run setup instead of the sidecar silently
+// failing provider calls.
+pub const REQUIRED_SECRET_KEYS: &[&str] = &["OPENAI_API_KEY", "ANTHROPIC_API_KEY"];
+
+fn entry(key: &str) -> Result<Entry, String> {
+    Entry::new(SERVICE, key).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn set_secret(key: String, value: String) -> Result<(), String> {
+    match entry(&key)?.set_password(&value).map_err(|e| e.to_string()) {
+        Ok(()) => {
+            println!("🔑 Secret '{}' stored in OS keyring", key);
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("⚠️ Failed to store secret '{}': {}", key, e);
+            Err(e)
+        }
+    }
+}
+
+#[tauri::command]
+pub fn get_secret(key: String) -> Result<Option<String>, String> {
+    match entry(&key)?.get_password() {
+        Ok(value) => {
+            println!("🔑 Secret '{}' read from OS keyring", key);
+            Ok(Some(value))
+        }
+        Err(keyring::Error::NoEntry) => {
+            println!("ℹ️ Secret '{}' not found in OS keyring", key);
+            Ok(None)
+        }
+        Err(e) => {
+            eprintln!("⚠️ Failed to read secret '{}': {}", key, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn delete_secret(key: String) -> Result<(), String> {
+    match entry(&key)?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => {
+            println!("🔑 Secret '{}' deleted from OS keyring", key);
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("⚠️ Failed to delete secret '{}': {}", key, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+// Reads every required key from the keyring, returning the ones found
+// (for injection into the sidecar's environment) alongside the ones missing
+// (for the `secrets_missing` event).
+pub fn load_required_secrets() -> (Vec<(String, String)>, Vec<String>) {
+    let mut found = Vec::new();
+    let mut missing = Vec::new();
+
+    for key in REQUIRED_SECRET_KEYS {
+        match entry(key).and_then(|e| e.get_password().map_err(|e| e.to_string())) {
+            Ok(value) => found.push((key.to_string(), value)),
+            Err(_) => missing.push(key.to_string()),
+        }
+    }
+
+    (found, missing)
+}